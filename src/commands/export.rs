@@ -0,0 +1,86 @@
+// src/commands/export.rs
+/*
+ * `export` subcommand: flush collected readings to durable storage.
+ */
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Args;
+use futures_util::StreamExt;
+
+use crate::commands::Command;
+use crate::sensor::{DemoSensor, SensorSet};
+use crate::tsfile::TsFileWriter;
+use crate::Result;
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// Path of the `.tsfile` to write
+    #[arg(short, long, default_value = "out.tsfile")]
+    output: PathBuf,
+
+    /// Device path readings are recorded under (e.g. `root.group.device`)
+    #[arg(long, default_value = "root.sg.device")]
+    device: String,
+
+    /// How often to poll registered sensors, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    interval_ms: u64,
+
+    /// Number of sampling ticks to collect before flushing
+    #[arg(long, default_value_t = 10)]
+    count: usize,
+}
+
+impl Command for ExportArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        let rt = tokio::runtime::Runtime::new()?;
+        rt.block_on(self.export(verbose))
+    }
+}
+
+impl ExportArgs {
+    async fn export(&self, verbose: bool) -> Result<()> {
+        let sensors = SensorSet::new(vec![Box::new(DemoSensor::new("demo"))]);
+        let mut stream = sensors.sample_stream(Duration::from_millis(self.interval_ms));
+        let mut writer = TsFileWriter::new();
+        let mut ticks = 0;
+
+        loop {
+            if ticks >= self.count {
+                break;
+            }
+
+            tokio::select! {
+                batch = stream.next() => {
+                    match batch {
+                        Some(readings) => {
+                            for reading in &readings {
+                                writer.write_reading(&self.device, reading);
+                            }
+                            ticks += 1;
+                        }
+                        None => break,
+                    }
+                }
+                _ = tokio::signal::ctrl_c() => {
+                    if verbose {
+                        println!("export: interrupted, flushing {ticks} collected tick(s)");
+                    }
+                    break;
+                }
+            }
+        }
+
+        writer.finish(&self.output)?;
+        if verbose {
+            println!(
+                "export: wrote {} tick(s) to {}",
+                ticks,
+                self.output.display()
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// src/commands/ingest.rs
+/*
+ * `ingest` subcommand: load readings from external sources.
+ */
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{FixedOffset, TimeZone, Utc};
+use clap::{Args, ValueEnum};
+
+use crate::commands::Command;
+use crate::timeparse::{self, DateTime};
+use crate::{Error, Result};
+
+#[derive(Args, Debug)]
+pub struct IngestArgs {
+    /// CSV/log file of `timestamp,sensor,value` rows to ingest
+    source: PathBuf,
+
+    /// How per-row timestamps (and --since/--until) are parsed
+    #[arg(long, value_enum, default_value_t = TimeFormat::Fuzzy)]
+    time_format: TimeFormat,
+
+    /// Drop rows timestamped before this instant
+    #[arg(long)]
+    since: Option<String>,
+
+    /// Drop rows timestamped after this instant
+    #[arg(long)]
+    until: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum TimeFormat {
+    /// Loosely-formatted human dates, e.g. `Jan 2 2024 3:04 PM`
+    Fuzzy,
+    /// RFC 3339 / ISO 8601, e.g. `2024-01-02T15:04:00Z`
+    Iso,
+    /// Milliseconds since the Unix epoch
+    Epoch,
+}
+
+impl TimeFormat {
+    fn parse(self, s: &str) -> Result<DateTime> {
+        match self {
+            TimeFormat::Fuzzy => timeparse::parse_timestamp(s),
+            TimeFormat::Iso => DateTime::parse_from_rfc3339(s)
+                .map_err(|e| Error::Other(format!("bad ISO timestamp {s:?}: {e}"))),
+            TimeFormat::Epoch => {
+                let millis: i64 = s
+                    .parse()
+                    .map_err(|_| Error::Other(format!("bad epoch millis {s:?}")))?;
+                Utc.timestamp_millis_opt(millis)
+                    .single()
+                    .map(|dt| dt.with_timezone(&FixedOffset::east_opt(0).unwrap()))
+                    .ok_or_else(|| Error::Other(format!("out-of-range epoch millis {s:?}")))
+            }
+        }
+    }
+}
+
+impl Command for IngestArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        let since = self.since.as_deref().map(|s| self.time_format.parse(s)).transpose()?;
+        let until = self.until.as_deref().map(|s| self.time_format.parse(s)).transpose()?;
+
+        let contents = fs::read_to_string(&self.source)?;
+        let mut kept = 0;
+        let mut skipped = 0;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.splitn(3, ',');
+            let (Some(ts_str), Some(name), Some(value_str)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                skipped += 1;
+                continue;
+            };
+
+            let timestamp = self.time_format.parse(ts_str.trim())?;
+            if since.is_some_and(|s| timestamp < s) || until.is_some_and(|u| timestamp > u) {
+                skipped += 1;
+                continue;
+            }
+
+            let value: Option<f32> = value_str.trim().parse().ok();
+            kept += 1;
+            if verbose {
+                println!("{timestamp} {} = {value:?}", name.trim());
+            }
+        }
+
+        if verbose {
+            println!("ingest: kept {kept} row(s), skipped {skipped}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// src/commands/kernel.rs
+/*
+ * `kernel` subcommand: install, start, or uninstall the embedded
+ * Jupyter kernel. Mirrors the top-level CLI's own pattern of a
+ * subcommand enum matched and delegated to each variant's `Command`
+ * impl, just one level down.
+ */
+
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+
+use crate::commands::Command;
+use crate::kernel::{server::KernelServer, spec};
+use crate::Result;
+
+#[derive(Args, Debug)]
+pub struct KernelArgs {
+    #[command(subcommand)]
+    command: KernelCommands,
+}
+
+#[derive(Subcommand, Debug)]
+enum KernelCommands {
+    /// Register this executable as a Jupyter kernel
+    Install(InstallArgs),
+    /// Serve a running kernel session
+    Start(StartArgs),
+    /// Remove the registered kernelspec
+    Uninstall(UninstallArgs),
+}
+
+impl Command for KernelArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        match &self.command {
+            KernelCommands::Install(cmd) => cmd.run(verbose),
+            KernelCommands::Start(cmd) => cmd.run(verbose),
+            KernelCommands::Uninstall(cmd) => cmd.run(verbose),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct InstallArgs {
+    /// Jupyter data directory (e.g. `~/.local/share/jupyter`)
+    #[arg(long)]
+    jupyter_data_dir: PathBuf,
+}
+
+impl Command for InstallArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        let exe = std::env::current_exe()?;
+        let path = spec::install(&self.jupyter_data_dir, &exe)?;
+        if verbose {
+            println!("kernel install: wrote {}", path.display());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct StartArgs {
+    /// Path to the connection file Jupyter generates for this session
+    #[arg(short = 'f', long = "connection-file")]
+    connection_file: PathBuf,
+
+    /// `.tsfile` to expose to `select`/`devices`/`sensors` queries
+    #[arg(long)]
+    tsfile: Option<PathBuf>,
+}
+
+impl Command for StartArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        let conn = crate::kernel::connection::ConnectionInfo::read(&self.connection_file)?;
+        if verbose {
+            println!("kernel start: binding shell on {}", conn.shell_endpoint());
+        }
+        let mut server = KernelServer::bind(&conn, self.tsfile.as_deref())?;
+        server.run()
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct UninstallArgs {
+    /// Jupyter data directory (e.g. `~/.local/share/jupyter`)
+    #[arg(long)]
+    jupyter_data_dir: PathBuf,
+}
+
+impl Command for UninstallArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        spec::uninstall(&self.jupyter_data_dir)?;
+        if verbose {
+            println!("kernel uninstall: removed kernelspec");
+        }
+        Ok(())
+    }
+}
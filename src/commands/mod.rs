@@ -0,0 +1,21 @@
+// src/commands/mod.rs
+/*
+ * Subcommand surface for the `iot` binary.
+ *
+ * Each subcommand owns its own argument struct and implements `Command`,
+ * so the top-level CLI only has to match on the variant and delegate.
+ */
+
+use crate::Result;
+
+pub mod export;
+pub mod ingest;
+pub mod kernel;
+pub mod sample;
+pub mod serve;
+
+/// Behavior shared by every subcommand.
+pub trait Command {
+    /// Run the subcommand, given the top-level `--verbose` flag.
+    fn run(&self, verbose: bool) -> Result<()>;
+}
@@ -0,0 +1,28 @@
+// src/commands/sample.rs
+/*
+ * `sample` subcommand: take one-off sensor readings.
+ */
+
+use clap::Args;
+
+use crate::commands::Command;
+use crate::sensor::{DemoSensor, Sensor};
+use crate::Result;
+
+#[derive(Args, Debug)]
+pub struct SampleArgs {}
+
+impl Command for SampleArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        let mut sensors: Vec<Box<dyn Sensor>> = vec![Box::new(DemoSensor::new("demo"))];
+
+        for sensor in &mut sensors {
+            let value = sensor.read();
+            if verbose {
+                println!("{}: {:?}", sensor.name(), value);
+            }
+        }
+
+        Ok(())
+    }
+}
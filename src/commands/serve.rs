@@ -0,0 +1,59 @@
+// src/commands/serve.rs
+/*
+ * `serve` subcommand: continuously poll registered sensors.
+ */
+
+use std::time::Duration;
+
+use clap::Args;
+use futures_util::StreamExt;
+
+use crate::commands::Command;
+use crate::sensor::{DemoSensor, SensorSet};
+use crate::Result;
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// How often to poll registered sensors, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    interval_ms: u64,
+}
+
+impl Command for ServeArgs {
+    fn run(&self, verbose: bool) -> Result<()> {
+        let rt = tokio::runtime::Runtime::new()?;
+        rt.block_on(self.serve(verbose))
+    }
+}
+
+impl ServeArgs {
+    async fn serve(&self, verbose: bool) -> Result<()> {
+        let sensors = SensorSet::new(vec![Box::new(DemoSensor::new("demo"))]);
+        let mut stream = sensors.sample_stream(Duration::from_millis(self.interval_ms));
+
+        loop {
+            tokio::select! {
+                batch = stream.next() => {
+                    match batch {
+                        Some(readings) => {
+                            if verbose {
+                                for reading in &readings {
+                                    println!("{}: {:?}", reading.name, reading.value);
+                                }
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                _ = tokio::signal::ctrl_c() => {
+                    if verbose {
+                        println!("serve: shutting down");
+                    }
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
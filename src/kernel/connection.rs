@@ -0,0 +1,51 @@
+// src/kernel/connection.rs
+/*
+ * The connection file Jupyter writes before launching a kernel, telling
+ * it which ports/transport/signing key to use for each ZeroMQ channel.
+ */
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionInfo {
+    pub ip: String,
+    pub transport: String,
+    pub shell_port: u16,
+    pub iopub_port: u16,
+    pub control_port: u16,
+    pub stdin_port: u16,
+    pub hb_port: u16,
+    pub signature_scheme: String,
+    pub key: String,
+}
+
+impl ConnectionInfo {
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    fn endpoint(&self, port: u16) -> String {
+        format!("{}://{}:{}", self.transport, self.ip, port)
+    }
+
+    pub fn shell_endpoint(&self) -> String {
+        self.endpoint(self.shell_port)
+    }
+
+    pub fn iopub_endpoint(&self) -> String {
+        self.endpoint(self.iopub_port)
+    }
+
+    pub fn control_endpoint(&self) -> String {
+        self.endpoint(self.control_port)
+    }
+
+    pub fn heartbeat_endpoint(&self) -> String {
+        self.endpoint(self.hb_port)
+    }
+}
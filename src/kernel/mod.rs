@@ -0,0 +1,15 @@
+// src/kernel/mod.rs
+/*
+ * Embeds a Jupyter kernel so collected sensor readings and stored
+ * `.tsfile` data can be explored interactively: `connection` models the
+ * connection file Jupyter hands the kernel, `protocol` implements the
+ * signed multipart wire format, `query` is the small language cells are
+ * evaluated as, `server` drives the ZeroMQ channels, and `spec` manages
+ * the kernelspec Jupyter uses to discover this kernel at all.
+ */
+
+pub mod connection;
+pub mod protocol;
+pub mod query;
+pub mod server;
+pub mod spec;
@@ -0,0 +1,130 @@
+// src/kernel/protocol.rs
+/*
+ * Jupyter messaging protocol (v5.3): each message is a multipart ZeroMQ
+ * frame set of
+ *
+ *   [identities...] <IDS|MSG> [hmac] [header] [parent_header] [metadata] [content]
+ *
+ * where `hmac` signs the four JSON frames that follow it. See
+ * https://jupyter-client.readthedocs.io/en/stable/messaging.html
+ */
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::{Error, Result};
+
+pub const DELIMITER: &[u8] = b"<IDS|MSG>";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub msg_id: String,
+    pub session: String,
+    pub username: String,
+    pub date: String,
+    pub msg_type: String,
+    pub version: String,
+}
+
+/// A fully decoded Jupyter message, minus the routing identity frames.
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub header: Header,
+    pub parent_header: Value,
+    pub metadata: Value,
+    pub content: Value,
+}
+
+impl Message {
+    pub fn reply(&self, msg_type: &str, content: Value) -> Message {
+        Message {
+            header: Header {
+                msg_id: self.header.msg_id.clone() + "_reply",
+                session: self.header.session.clone(),
+                username: "kernel".to_string(),
+                date: self.header.date.clone(),
+                msg_type: msg_type.to_string(),
+                version: self.header.version.clone(),
+            },
+            parent_header: serde_json::to_value(&self.header).unwrap_or(Value::Null),
+            metadata: Value::Object(Default::default()),
+            content,
+        }
+    }
+}
+
+/// Signs and verifies message frames with HMAC-SHA256, as the
+/// `signature_scheme: hmac-sha256` connection file entry requests.
+pub struct Signer {
+    key: Vec<u8>,
+}
+
+impl Signer {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.as_bytes().to_vec(),
+        }
+    }
+
+    fn mac(&self) -> Result<Hmac<Sha256>> {
+        Hmac::<Sha256>::new_from_slice(&self.key)
+            .map_err(|e| Error::Other(format!("bad hmac key: {e}")))
+    }
+
+    pub fn sign(&self, frames: &[&[u8]]) -> Result<String> {
+        if self.key.is_empty() {
+            return Ok(String::new());
+        }
+        let mut mac = self.mac()?;
+        for frame in frames {
+            mac.update(frame);
+        }
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    pub fn verify(&self, signature: &str, frames: &[&[u8]]) -> Result<bool> {
+        Ok(self.sign(frames)? == signature)
+    }
+}
+
+/// Parse the frames following the `<IDS|MSG>` delimiter (signature,
+/// header, parent_header, metadata, content) into a [`Message`],
+/// rejecting it if the HMAC signature doesn't match.
+pub fn decode(signer: &Signer, frames: &[Vec<u8>]) -> Result<Message> {
+    let [signature, header, parent_header, metadata, content] = frames else {
+        return Err(Error::Other("malformed message: expected 5 frames".to_string()));
+    };
+
+    if !signer.verify(
+        &String::from_utf8_lossy(signature),
+        &[header, parent_header, metadata, content],
+    )? {
+        return Err(Error::Other("message signature mismatch".to_string()));
+    }
+
+    Ok(Message {
+        header: serde_json::from_slice(header)?,
+        parent_header: serde_json::from_slice(parent_header)?,
+        metadata: serde_json::from_slice(metadata)?,
+        content: serde_json::from_slice(content)?,
+    })
+}
+
+/// Serialize a [`Message`] back into the five signed frames.
+pub fn encode(signer: &Signer, message: &Message) -> Result<Vec<Vec<u8>>> {
+    let header = serde_json::to_vec(&message.header)?;
+    let parent_header = serde_json::to_vec(&message.parent_header)?;
+    let metadata = serde_json::to_vec(&message.metadata)?;
+    let content = serde_json::to_vec(&message.content)?;
+    let signature = signer.sign(&[&header, &parent_header, &metadata, &content])?;
+
+    Ok(vec![
+        signature.into_bytes(),
+        header,
+        parent_header,
+        metadata,
+        content,
+    ])
+}
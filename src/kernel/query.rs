@@ -0,0 +1,35 @@
+// src/kernel/query.rs
+/*
+ * A tiny query language for exploring collected readings and stored
+ * `.tsfile` data from inside a Jupyter cell, e.g.:
+ *
+ *   devices
+ *   sensors root.sg.device
+ *   select root.sg.device temperature
+ */
+
+use crate::tsfile::TsFileReader;
+use crate::{Error, Result};
+
+pub enum QueryResult {
+    /// Plain text, shown as the cell's `text/plain` output.
+    Text(String),
+    /// A `(timestamp, value)` table, shown as `display_data`.
+    Table(Vec<(i64, f32)>),
+}
+
+/// Evaluate one line of the query language against an open `.tsfile`.
+pub fn eval(reader: &mut TsFileReader, query: &str) -> Result<QueryResult> {
+    let tokens: Vec<&str> = query.split_whitespace().collect();
+    match tokens.as_slice() {
+        ["devices"] => Ok(QueryResult::Text(reader.device_iter().collect::<Vec<_>>().join("\n"))),
+        ["sensors", device] => {
+            Ok(QueryResult::Text(reader.sensor_iter(device).collect::<Vec<_>>().join("\n")))
+        }
+        ["select", device, sensor] => Ok(QueryResult::Table(reader.read_points(device, sensor)?)),
+        [] => Ok(QueryResult::Text(String::new())),
+        _ => Err(Error::Other(format!(
+            "unrecognized query {query:?}; expected `devices`, `sensors <device>`, or `select <device> <sensor>`"
+        ))),
+    }
+}
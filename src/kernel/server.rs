@@ -0,0 +1,256 @@
+// src/kernel/server.rs
+/*
+ * The running kernel: binds the shell/iopub/control/heartbeat ZeroMQ
+ * sockets named in the connection file and answers `kernel_info_request`
+ * and `execute_request` messages, evaluating cell contents as queries
+ * (see `kernel::query`) over a `.tsfile`.
+ */
+
+use serde_json::json;
+
+use crate::kernel::connection::ConnectionInfo;
+use crate::kernel::protocol::{self, Message, Signer};
+use crate::kernel::query::{self, QueryResult};
+use crate::tsfile::TsFileReader;
+use crate::{Error, Result};
+
+pub struct KernelServer {
+    shell: zmq::Socket,
+    iopub: zmq::Socket,
+    control: zmq::Socket,
+    heartbeat: zmq::Socket,
+    signer: Signer,
+    reader: Option<TsFileReader>,
+    execution_count: u64,
+}
+
+impl KernelServer {
+    pub fn bind(conn: &ConnectionInfo, tsfile: Option<&std::path::Path>) -> Result<Self> {
+        let ctx = zmq::Context::new();
+
+        let shell = ctx
+            .socket(zmq::ROUTER)
+            .map_err(|e| Error::Other(format!("zmq shell socket: {e}")))?;
+        shell
+            .bind(&conn.shell_endpoint())
+            .map_err(|e| Error::Other(format!("bind shell: {e}")))?;
+
+        let iopub = ctx
+            .socket(zmq::PUB)
+            .map_err(|e| Error::Other(format!("zmq iopub socket: {e}")))?;
+        iopub
+            .bind(&conn.iopub_endpoint())
+            .map_err(|e| Error::Other(format!("bind iopub: {e}")))?;
+
+        let control = ctx
+            .socket(zmq::ROUTER)
+            .map_err(|e| Error::Other(format!("zmq control socket: {e}")))?;
+        control
+            .bind(&conn.control_endpoint())
+            .map_err(|e| Error::Other(format!("bind control: {e}")))?;
+
+        let heartbeat = ctx
+            .socket(zmq::REP)
+            .map_err(|e| Error::Other(format!("zmq heartbeat socket: {e}")))?;
+        heartbeat
+            .bind(&conn.heartbeat_endpoint())
+            .map_err(|e| Error::Other(format!("bind heartbeat: {e}")))?;
+
+        let reader = tsfile.map(TsFileReader::open).transpose()?;
+
+        Ok(Self {
+            shell,
+            iopub,
+            control,
+            heartbeat,
+            signer: Signer::new(&conn.key),
+            reader,
+            execution_count: 0,
+        })
+    }
+
+    /// Serve requests until the process is killed.
+    pub fn run(&mut self) -> Result<()> {
+        let mut poll_items = [
+            self.shell.as_poll_item(zmq::POLLIN),
+            self.control.as_poll_item(zmq::POLLIN),
+            self.heartbeat.as_poll_item(zmq::POLLIN),
+        ];
+
+        loop {
+            zmq::poll(&mut poll_items, -1).map_err(|e| Error::Other(format!("poll: {e}")))?;
+
+            if poll_items[0].is_readable() {
+                self.handle_channel(ChannelKind::Shell)?;
+            }
+            if poll_items[1].is_readable() {
+                self.handle_channel(ChannelKind::Control)?;
+            }
+            if poll_items[2].is_readable() {
+                let payload = self
+                    .heartbeat
+                    .recv_bytes(0)
+                    .map_err(|e| Error::Other(format!("recv heartbeat: {e}")))?;
+                self.heartbeat
+                    .send(payload, 0)
+                    .map_err(|e| Error::Other(format!("send heartbeat: {e}")))?;
+            }
+        }
+    }
+
+    fn handle_channel(&mut self, channel: ChannelKind) -> Result<()> {
+        let socket = match channel {
+            ChannelKind::Shell => &self.shell,
+            ChannelKind::Control => &self.control,
+        };
+
+        let mut parts = socket
+            .recv_multipart(0)
+            .map_err(|e| Error::Other(format!("recv: {e}")))?;
+
+        let delimiter_idx = parts
+            .iter()
+            .position(|p| p.as_slice() == protocol::DELIMITER)
+            .ok_or_else(|| Error::Other("missing <IDS|MSG> delimiter".to_string()))?;
+        let identities: Vec<Vec<u8>> = parts.drain(..delimiter_idx).collect();
+        parts.remove(0); // the delimiter itself
+
+        let request = protocol::decode(&self.signer, &parts)?;
+        let reply = match request.header.msg_type.as_str() {
+            "kernel_info_request" => Some(self.kernel_info_reply(&request)),
+            "execute_request" => Some(self.execute_reply(&request)?),
+            _ => None,
+        };
+
+        if let Some(reply) = reply {
+            self.send(channel, &identities, &reply)?;
+        }
+        Ok(())
+    }
+
+    fn kernel_info_reply(&self, request: &Message) -> Message {
+        request.reply(
+            "kernel_info_reply",
+            json!({
+                "status": "ok",
+                "protocol_version": "5.3",
+                "implementation": "iot",
+                "implementation_version": env!("CARGO_PKG_VERSION"),
+                "language_info": {
+                    "name": "iot-query",
+                    "mimetype": "text/plain",
+                    "file_extension": ".iotq",
+                },
+                "banner": "IoT sensor/tsfile query kernel",
+            }),
+        )
+    }
+
+    fn execute_reply(&mut self, request: &Message) -> Result<Message> {
+        self.execution_count += 1;
+        let code = request
+            .content
+            .get("code")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+
+        let outcome = match &mut self.reader {
+            Some(reader) => query::eval(reader, code),
+            None => Err(Error::Other(
+                "no .tsfile loaded for this kernel".to_string(),
+            )),
+        };
+
+        match outcome {
+            Ok(result) => {
+                self.publish_result(request, &result)?;
+                Ok(request.reply(
+                    "execute_reply",
+                    json!({ "status": "ok", "execution_count": self.execution_count }),
+                ))
+            }
+            Err(err) => {
+                self.publish_error(request, &err)?;
+                Ok(request.reply(
+                    "execute_reply",
+                    json!({
+                        "status": "error",
+                        "execution_count": self.execution_count,
+                        "ename": "QueryError",
+                        "evalue": err.to_string(),
+                        "traceback": [err.to_string()],
+                    }),
+                ))
+            }
+        }
+    }
+
+    fn publish_result(&self, request: &Message, result: &QueryResult) -> Result<()> {
+        match result {
+            QueryResult::Text(text) => self.publish(
+                request,
+                "execute_result",
+                json!({
+                    "data": { "text/plain": text },
+                    "metadata": {},
+                    "execution_count": self.execution_count,
+                }),
+            ),
+            // Tables are point-in-time data, not "the result of this
+            // expression" in the execute_result sense, so the Jupyter
+            // messaging spec has them travel as display_data instead
+            // (no execution_count).
+            QueryResult::Table(points) => self.publish(
+                request,
+                "display_data",
+                json!({
+                    "data": {
+                        "text/plain": format!("{} point(s)", points.len()),
+                        "application/json": points,
+                    },
+                    "metadata": {},
+                }),
+            ),
+        }
+    }
+
+    fn publish_error(&self, request: &Message, err: &Error) -> Result<()> {
+        self.publish(
+            request,
+            "error",
+            json!({
+                "ename": "QueryError",
+                "evalue": err.to_string(),
+                "traceback": [err.to_string()],
+            }),
+        )
+    }
+
+    fn publish(&self, request: &Message, msg_type: &str, content: serde_json::Value) -> Result<()> {
+        let message = request.reply(msg_type, content);
+        let frames = protocol::encode(&self.signer, &message)?;
+        self.iopub
+            .send_multipart(frames, 0)
+            .map_err(|e| Error::Other(format!("publish iopub: {e}")))
+    }
+
+    fn send(&self, channel: ChannelKind, identities: &[Vec<u8>], reply: &Message) -> Result<()> {
+        let socket = match channel {
+            ChannelKind::Shell => &self.shell,
+            ChannelKind::Control => &self.control,
+        };
+
+        let mut frames: Vec<Vec<u8>> = identities.to_vec();
+        frames.push(protocol::DELIMITER.to_vec());
+        frames.extend(protocol::encode(&self.signer, reply)?);
+        socket
+            .send_multipart(frames, 0)
+            .map_err(|e| Error::Other(format!("send reply: {e}")))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ChannelKind {
+    Shell,
+    Control,
+}
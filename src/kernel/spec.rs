@@ -0,0 +1,45 @@
+// src/kernel/spec.rs
+/*
+ * The kernelspec Jupyter reads to discover and launch this kernel.
+ * `jupyter --data-dir`/`kernels/<name>/kernel.json` points at the
+ * executable's own `kernel start` subcommand with `{connection_file}`
+ * substituted in by Jupyter at launch.
+ */
+
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::Result;
+
+pub const KERNEL_NAME: &str = "iot";
+
+/// Directory Jupyter expects `kernel.json` (and friends) to live in.
+pub fn kernelspec_dir(jupyter_data_dir: &std::path::Path) -> PathBuf {
+    jupyter_data_dir.join("kernels").join(KERNEL_NAME)
+}
+
+/// Write `kernel.json` pointing at `exe_path kernel start -f {connection_file}`.
+pub fn install(jupyter_data_dir: &std::path::Path, exe_path: &std::path::Path) -> Result<PathBuf> {
+    let dir = kernelspec_dir(jupyter_data_dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let spec = json!({
+        "argv": [exe_path.to_string_lossy(), "kernel", "start", "-f", "{connection_file}"],
+        "display_name": "IoT",
+        "language": "iot-query",
+    });
+
+    let path = dir.join("kernel.json");
+    std::fs::write(&path, serde_json::to_string_pretty(&spec)?)?;
+    Ok(path)
+}
+
+/// Remove the kernelspec directory written by [`install`].
+pub fn uninstall(jupyter_data_dir: &std::path::Path) -> Result<()> {
+    let dir = kernelspec_dir(jupyter_data_dir);
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    Ok(())
+}
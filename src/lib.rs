@@ -0,0 +1,13 @@
+// src/lib.rs
+/*
+ * Library crate for IoT
+ */
+
+pub mod commands;
+mod error;
+pub mod kernel;
+pub mod sensor;
+pub mod timeparse;
+pub mod tsfile;
+
+pub use error::{Error, Result};
@@ -3,18 +3,45 @@
  * Main executable for IoT
  */
 
-use clap::Parser;
-use iot::{Result, run};
+use clap::{Parser, Subcommand};
+use iot::commands::{
+    export::ExportArgs, ingest::IngestArgs, kernel::KernelArgs, sample::SampleArgs,
+    serve::ServeArgs, Command,
+};
+use iot::Result;
 
 #[derive(Parser)]
 #[command(version, about = "IoT - A Rust implementation")]
 struct Cli {
     /// Enable verbose output
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     verbose: bool,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Take one-off sensor readings
+    Sample(SampleArgs),
+    /// Flush collected readings to durable storage
+    Export(ExportArgs),
+    /// Continuously poll registered sensors
+    Serve(ServeArgs),
+    /// Load readings from external sources
+    Ingest(IngestArgs),
+    /// Install, start, or uninstall the embedded Jupyter kernel
+    Kernel(KernelArgs),
 }
 
 fn main() -> Result<()> {
     let args = Cli::parse();
-    run(args.verbose)
+    match &args.command {
+        Commands::Sample(cmd) => cmd.run(args.verbose),
+        Commands::Export(cmd) => cmd.run(args.verbose),
+        Commands::Serve(cmd) => cmd.run(args.verbose),
+        Commands::Ingest(cmd) => cmd.run(args.verbose),
+        Commands::Kernel(cmd) => cmd.run(args.verbose),
+    }
 }
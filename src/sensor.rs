@@ -0,0 +1,111 @@
+// src/sensor.rs
+/*
+ * Sensor sampling subsystem.
+ *
+ * A `Sensor` is anything that can produce a named reading on demand.
+ * `SensorSet::sample_stream` polls every registered sensor on a fixed
+ * interval and yields a batch of readings per tick, so callers can drive
+ * it with a plain `while let Some(batch) = stream.next().await`.
+ */
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::time::{self, Interval};
+
+/// A single sensor observation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reading {
+    /// Name of the sensor that produced this reading.
+    pub name: String,
+    /// The measured value, or `None` if this tick's read failed.
+    pub value: Option<f32>,
+    /// Wall-clock time the reading was taken.
+    pub timestamp: std::time::SystemTime,
+}
+
+/// A source of readings, polled once per tick.
+pub trait Sensor: Send {
+    /// Unique name used to label this sensor's readings.
+    fn name(&self) -> &str;
+
+    /// Take a single reading, returning `None` if the read failed.
+    fn read(&mut self) -> Option<f32>;
+}
+
+/// A trivial sensor used until real sensor backends are configured.
+pub struct DemoSensor {
+    name: String,
+    ticks: u32,
+}
+
+impl DemoSensor {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ticks: 0,
+        }
+    }
+}
+
+impl Sensor for DemoSensor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn read(&mut self) -> Option<f32> {
+        self.ticks += 1;
+        Some(self.ticks as f32)
+    }
+}
+
+/// A set of sensors polled together on a fixed interval.
+pub struct SensorSet {
+    sensors: Vec<Box<dyn Sensor>>,
+}
+
+impl SensorSet {
+    pub fn new(sensors: Vec<Box<dyn Sensor>>) -> Self {
+        Self { sensors }
+    }
+
+    /// Build a stream that yields one batch of readings (one per sensor)
+    /// every `interval`.
+    pub fn sample_stream(self, interval: Duration) -> SampleStream {
+        SampleStream {
+            sensors: self.sensors,
+            interval: time::interval(interval),
+        }
+    }
+}
+
+/// `Stream<Item = Vec<Reading>>` that ticks every fixed interval.
+pub struct SampleStream {
+    sensors: Vec<Box<dyn Sensor>>,
+    interval: Interval,
+}
+
+impl Stream for SampleStream {
+    type Item = Vec<Reading>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match this.interval.poll_tick(cx) {
+            Poll::Ready(_) => {
+                let readings = this
+                    .sensors
+                    .iter_mut()
+                    .map(|sensor| Reading {
+                        name: sensor.name().to_string(),
+                        value: sensor.read(),
+                        timestamp: std::time::SystemTime::now(),
+                    })
+                    .collect();
+                Poll::Ready(Some(readings))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
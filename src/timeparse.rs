@@ -0,0 +1,394 @@
+// src/timeparse.rs
+/*
+ * Loose, dateutil-style timestamp parsing for the ingest path.
+ *
+ * Unlike `DateTime::parse_from_rfc3339` et al., `parse_timestamp` accepts
+ * human-formatted dates such as `2024-01-02 15:04`, `Jan 2 2024`, or a
+ * bare `15:04`. The string is tokenized into numbers, month names,
+ * separators and am/pm markers; ambiguous numbers are classified as
+ * year/month/day by range (a value > 31 must be a year, > 12 must be a
+ * day) and by the caller's `dayfirst`/`yearfirst` preference. Any field
+ * left unspecified is filled in from a default instant (by default
+ * "now").
+ */
+
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
+
+use crate::{Error, Result};
+
+/// Timestamp type returned by [`parse_timestamp`].
+pub type DateTime = chrono::DateTime<FixedOffset>;
+
+/// Parsing preferences, mirroring `dateutil.parser.parserinfo`.
+#[derive(Debug, Clone)]
+pub struct ParserInfo {
+    /// Prefer DD-MM over MM-DD when a date is ambiguous.
+    pub dayfirst: bool,
+    /// Prefer YY-MM-DD over MM-DD-YY when a date is ambiguous.
+    pub yearfirst: bool,
+    /// Fields not found in the input are taken from this instant.
+    pub default: DateTime,
+}
+
+impl Default for ParserInfo {
+    fn default() -> Self {
+        Self {
+            dayfirst: false,
+            yearfirst: false,
+            default: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()),
+        }
+    }
+}
+
+/// Parse a loosely-formatted timestamp using default parser preferences.
+pub fn parse_timestamp(s: &str) -> Result<DateTime> {
+    parse_timestamp_with(s, &ParserInfo::default())
+}
+
+/// Parse a loosely-formatted timestamp with explicit parser preferences.
+pub fn parse_timestamp_with(s: &str, info: &ParserInfo) -> Result<DateTime> {
+    let (body, tz_offset) = strip_timezone(s.trim());
+
+    let mut year: Option<i32> = None;
+    let mut month: Option<u32> = None;
+    let mut day: Option<u32> = None;
+    let mut hour: Option<u32> = None;
+    let mut minute: Option<u32> = None;
+    let mut second: Option<u32> = None;
+    let mut meridiem_pm: Option<bool> = None;
+    // A bare single number tentatively classified as the month (because
+    // no day was known yet) so that a later month *name* can bump it
+    // into the day slot instead of clobbering it (e.g. `"2 Jan 2024"`).
+    let mut tentative_month: Option<u32> = None;
+
+    for chunk in body.split(|c: char| c.is_whitespace() || c == ',') {
+        if chunk.is_empty() {
+            continue;
+        }
+
+        if chunk.contains(':') {
+            let parts: Vec<&str> = chunk.split(':').collect();
+            hour = parts.first().and_then(|p| p.parse().ok());
+            minute = parts
+                .get(1)
+                .and_then(|p| p.trim_end_matches(|c: char| c.is_alphabetic()).parse().ok());
+            second = parts
+                .get(2)
+                .and_then(|p| p.trim_end_matches(|c: char| c.is_alphabetic()).parse().ok());
+            if let Some(m) = trailing_meridiem(chunk) {
+                meridiem_pm = Some(m);
+            }
+            continue;
+        }
+
+        if let Some(m) = trailing_meridiem(chunk) {
+            meridiem_pm = Some(m);
+            continue;
+        }
+
+        if chunk.chars().any(|c| c.is_ascii_digit())
+            && chunk.contains(|c: char| c == '-' || c == '/' || c == '.')
+        {
+            let nums: Vec<&str> = chunk
+                .split(|c: char| c == '-' || c == '/' || c == '.')
+                .filter(|p| !p.is_empty())
+                .collect();
+            assign_date_numbers(&nums, info, &mut year, &mut month, &mut day)?;
+            tentative_month = None;
+            continue;
+        }
+
+        if let Some(m) = month_name(chunk) {
+            if let Some(prev) = tentative_month.take() {
+                if day.is_none() {
+                    day = Some(prev);
+                }
+            }
+            month = Some(m);
+            continue;
+        }
+
+        if chunk.chars().all(|c| c.is_ascii_digit()) {
+            let ambiguous_month = month.is_none()
+                && day.is_none()
+                && chunk.parse::<i32>().is_ok_and(|v| (1..=12).contains(&v));
+            assign_date_numbers(&[chunk], info, &mut year, &mut month, &mut day)?;
+            tentative_month = if ambiguous_month { month } else { None };
+            continue;
+        }
+
+        // Unrecognized token (weekday name, tz abbreviation, ...): ignore.
+    }
+
+    if let (Some(h), Some(pm)) = (hour, meridiem_pm) {
+        hour = Some(to_24h(h, pm));
+    }
+
+    let year = year.unwrap_or_else(|| info.default.year());
+    let month = month.unwrap_or_else(|| info.default.month());
+    let day = day.unwrap_or_else(|| info.default.day());
+    let hour = hour.unwrap_or_else(|| info.default.hour());
+    let minute = minute.unwrap_or_else(|| info.default.minute());
+    let second = second.unwrap_or_else(|| info.default.second());
+
+    let date = NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| Error::Other(format!("invalid date in {s:?}")))?;
+    let time = NaiveTime::from_hms_opt(hour, minute, second)
+        .ok_or_else(|| Error::Other(format!("invalid time in {s:?}")))?;
+    let naive = date.and_time(time);
+
+    let offset = tz_offset.unwrap_or_else(|| *info.default.offset());
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .ok_or_else(|| Error::Other(format!("ambiguous local time in {s:?}")))
+}
+
+/// Classify 1-3 numeric tokens from a `-`/`/`/`.`-separated date chunk
+/// (or a single bare number) into year/month/day by magnitude, falling
+/// back to `dayfirst`/`yearfirst` when ambiguous.
+fn assign_date_numbers(
+    nums: &[&str],
+    info: &ParserInfo,
+    year: &mut Option<i32>,
+    month: &mut Option<u32>,
+    day: &mut Option<u32>,
+) -> Result<()> {
+    let mut values = Vec::new();
+    for n in nums {
+        let value: i32 = n
+            .parse()
+            .map_err(|_| Error::Other(format!("not a number: {n:?}")))?;
+        values.push(value);
+    }
+
+    match values.as_slice() {
+        [single] => {
+            if *single > 31 {
+                *year = Some(expand_2digit_year(*single));
+            } else if day.is_none() && (month.is_some() || *single > 12) {
+                *day = Some(*single as u32);
+            } else if month.is_none() {
+                *month = Some(*single as u32);
+            } else {
+                *day = Some(*single as u32);
+            }
+        }
+        [a, b] => {
+            if *a > 31 {
+                *year = Some(expand_2digit_year(*a));
+                *month = Some(*b as u32);
+            } else if *b > 31 {
+                *year = Some(expand_2digit_year(*b));
+                *month = Some(*a as u32);
+            } else {
+                let (m, d) = order_month_day(*a, *b, info.dayfirst);
+                *month = Some(m);
+                *day = Some(d);
+            }
+        }
+        [a, b, c] => {
+            let (y, m, d) = classify_ymd(*a, *b, *c, info);
+            *year = Some(expand_2digit_year(y));
+            *month = Some(m);
+            *day = Some(d);
+        }
+        _ => return Err(Error::Other("too many numbers in date".to_string())),
+    }
+    Ok(())
+}
+
+/// Expand a bare 2-digit year (`24` -> `2024`, `69` -> `1969`) found in
+/// the year slot. Values already in a 4-digit-like range are untouched.
+fn expand_2digit_year(value: i32) -> i32 {
+    if !(0..100).contains(&value) {
+        return value;
+    }
+    if value <= 68 {
+        value + 2000
+    } else {
+        value + 1900
+    }
+}
+
+fn classify_ymd(a: i32, b: i32, c: i32, info: &ParserInfo) -> (i32, u32, u32) {
+    let is_year = |v: i32| v > 31;
+
+    if is_year(a) {
+        let (m, d) = order_month_day(b, c, info.dayfirst);
+        (a, m, d)
+    } else if is_year(c) {
+        let (m, d) = order_month_day(a, b, info.dayfirst);
+        (c, m, d)
+    } else if info.yearfirst {
+        let (m, d) = order_month_day(b, c, info.dayfirst);
+        (a, m, d)
+    } else if info.dayfirst {
+        (c, b as u32, a as u32)
+    } else {
+        (c, a as u32, b as u32)
+    }
+}
+
+fn order_month_day(a: i32, b: i32, dayfirst: bool) -> (u32, u32) {
+    if a > 12 {
+        (b as u32, a as u32)
+    } else if b > 12 {
+        (a as u32, b as u32)
+    } else if dayfirst {
+        (b as u32, a as u32)
+    } else {
+        (a as u32, b as u32)
+    }
+}
+
+fn to_24h(hour: u32, pm: bool) -> u32 {
+    match (hour, pm) {
+        (12, false) => 0,
+        (12, true) => 12,
+        (h, true) => h + 12,
+        (h, false) => h,
+    }
+}
+
+fn trailing_meridiem(chunk: &str) -> Option<bool> {
+    let lower = chunk.to_ascii_lowercase();
+    if lower.ends_with("pm") || lower == "p.m." {
+        Some(true)
+    } else if lower.ends_with("am") || lower == "a.m." {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn month_name(chunk: &str) -> Option<u32> {
+    const MONTHS: &[&str] = &[
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let lower = chunk.to_ascii_lowercase();
+    MONTHS
+        .iter()
+        .position(|m| lower.starts_with(m))
+        .map(|idx| (idx + 1) as u32)
+}
+
+/// Strip a trailing `Z` or `+HH:MM`/`-HHMM` offset, returning the
+/// remaining string and the parsed offset, if any.
+fn strip_timezone(s: &str) -> (&str, Option<FixedOffset>) {
+    if let Some(rest) = s.strip_suffix('Z') {
+        return (rest.trim_end(), Some(FixedOffset::east_opt(0).unwrap()));
+    }
+
+    let bytes = s.as_bytes();
+    for idx in (1..bytes.len()).rev() {
+        let c = bytes[idx] as char;
+        if c == '+' || c == '-' {
+            let candidate = &s[idx + 1..];
+            let digits: String = candidate.chars().filter(|c| c.is_ascii_digit()).collect();
+            if digits.len() == 4 && candidate.chars().all(|c| c.is_ascii_digit() || c == ':') {
+                let hours: i32 = digits[0..2].parse().unwrap_or(0);
+                let minutes: i32 = digits[2..4].parse().unwrap_or(0);
+                let total = hours * 3600 + minutes * 60;
+                let offset = if c == '-' { -total } else { total };
+                return (s[..idx].trim_end(), FixedOffset::east_opt(offset));
+            }
+        }
+    }
+
+    (s, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_default() -> ParserInfo {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        ParserInfo {
+            dayfirst: false,
+            yearfirst: false,
+            default: utc.with_ymd_and_hms(2020, 6, 15, 10, 30, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn bare_time_fills_date_from_default() {
+        let dt = parse_timestamp_with("15:04", &fixed_default()).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2020, 6, 15));
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (15, 4, 0));
+    }
+
+    #[test]
+    fn month_name_and_year() {
+        let dt = parse_timestamp_with("Jan 2 2024", &fixed_default()).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 1, 2));
+        // time of day falls back to the default instant
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (10, 30, 0));
+    }
+
+    #[test]
+    fn iso_like_date_and_time() {
+        let dt = parse_timestamp_with("2024-01-02 15:04", &fixed_default()).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 1, 2));
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (15, 4, 0));
+    }
+
+    #[test]
+    fn trailing_day_unaffected_by_year_fix() {
+        // Regression test: the day/month numbers must not be treated as
+        // 2-digit years just because they're 2 digits long.
+        let dt = parse_timestamp_with("2024-12-31", &fixed_default()).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 12, 31));
+    }
+
+    #[test]
+    fn am_pm_markers() {
+        let dt = parse_timestamp_with("Jan 2 2024 3:04 PM", &fixed_default()).unwrap();
+        assert_eq!((dt.hour(), dt.minute()), (15, 4));
+
+        let dt = parse_timestamp_with("Jan 2 2024 12:00 AM", &fixed_default()).unwrap();
+        assert_eq!(dt.hour(), 0);
+    }
+
+    #[test]
+    fn two_digit_year_expansion() {
+        let dt = parse_timestamp_with("01/02/24", &fixed_default()).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 1, 2));
+
+        let dt = parse_timestamp_with("01/02/69", &fixed_default()).unwrap();
+        assert_eq!(dt.year(), 1969);
+    }
+
+    #[test]
+    fn timezone_offset() {
+        let dt = parse_timestamp_with("2024-01-02 15:04 +0500", &fixed_default()).unwrap();
+        assert_eq!(dt.offset().local_minus_utc(), 5 * 3600);
+        assert_eq!((dt.hour(), dt.minute()), (15, 4));
+    }
+
+    #[test]
+    fn timezone_z_suffix() {
+        let dt = parse_timestamp_with("2024-01-02 15:04Z", &fixed_default()).unwrap();
+        assert_eq!(dt.offset().local_minus_utc(), 0);
+    }
+
+    #[test]
+    fn day_before_month_name() {
+        // Regression test: a leading day number must not be clobbered
+        // when a month name follows it.
+        let dt = parse_timestamp_with("2 Jan 2024", &fixed_default()).unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 1, 2));
+    }
+
+    #[test]
+    fn two_number_date_day_over_twelve() {
+        // Regression test: a two-number date must apply the same
+        // `> 12 => day` heuristic the three-number case already does.
+        let dt = parse_timestamp_with("13/05", &fixed_default()).unwrap();
+        assert_eq!((dt.month(), dt.day()), (5, 13));
+
+        let dt = parse_timestamp_with("05/13", &fixed_default()).unwrap();
+        assert_eq!((dt.month(), dt.day()), (5, 13));
+    }
+}
@@ -0,0 +1,24 @@
+// src/tsfile/format.rs
+/*
+ * On-disk layout for `.tsfile`, modeled on Apache IoTDB's TsFile.
+ *
+ * A file is a sequence of chunks (one per device path + sensor name),
+ * each holding every buffered (timestamp, value) point of that series,
+ * followed by a trailing index mapping device -> sensor -> chunk
+ * locations, and a fixed-size footer pointing at the start of that index.
+ *
+ *   [MAGIC] [chunk]... [index] [index_offset: u64] [MAGIC]
+ */
+
+pub const MAGIC: &[u8; 4] = b"TSF1";
+
+/// Location and summary of one device+sensor's readings within the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkMeta {
+    /// Byte offset of the chunk's first page.
+    pub offset: u64,
+    /// Number of (timestamp, value) points in the chunk.
+    pub num_points: u64,
+    pub min_timestamp: i64,
+    pub max_timestamp: i64,
+}
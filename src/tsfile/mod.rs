@@ -0,0 +1,80 @@
+// src/tsfile/mod.rs
+/*
+ * Columnar time-series file format, modeled on Apache IoTDB's TsFile.
+ *
+ * Readings are grouped by device path (`root.group.device`) and sensor
+ * name, stored as chunks of (timestamp, value) pages, with a trailing
+ * metadata index so a reader can binary search straight to a chunk
+ * instead of scanning the file.
+ */
+
+mod format;
+mod reader;
+mod writer;
+
+pub use format::ChunkMeta;
+pub use reader::TsFileReader;
+pub use writer::TsFileWriter;
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+    use crate::sensor::Reading;
+
+    fn reading(name: &str, value: f32, at: SystemTime) -> Reading {
+        Reading {
+            name: name.to_string(),
+            value: Some(value),
+            timestamp: at,
+        }
+    }
+
+    #[test]
+    fn write_then_read_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "iot-tsfile-test-{}-{:?}.tsfile",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let t1 = t0 + Duration::from_secs(60);
+
+        let mut writer = TsFileWriter::new();
+        writer.write_reading("root.sg.device", &reading("temperature", 21.5, t0));
+        writer.write_reading("root.sg.device", &reading("temperature", 22.0, t1));
+        writer.write_reading("root.sg.device", &reading("humidity", 40.0, t0));
+        writer.write_reading("root.sg.other", &reading("temperature", 99.9, t0));
+        writer.finish(&path).unwrap();
+
+        let mut reader = TsFileReader::open(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let devices: Vec<&str> = reader.device_iter().collect();
+        assert_eq!(devices, vec!["root.sg.device", "root.sg.other"]);
+
+        let sensors: Vec<&str> = reader.sensor_iter("root.sg.device").collect();
+        assert_eq!(sensors, vec!["humidity", "temperature"]);
+
+        let meta = reader.search_meta("root.sg.device", "temperature").unwrap();
+        assert_eq!(meta.num_points, 2);
+
+        assert!(reader.search_meta("root.sg.device", "pressure").is_none());
+
+        let points = reader.read_points("root.sg.device", "temperature").unwrap();
+        let expected_t0 = t0
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let expected_t1 = t1
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        assert_eq!(points, vec![(expected_t0, 21.5), (expected_t1, 22.0)]);
+
+        let other = reader.read_points("root.sg.other", "temperature").unwrap();
+        assert_eq!(other, vec![(expected_t0, 99.9)]);
+    }
+}
@@ -0,0 +1,139 @@
+// src/tsfile/reader.rs
+/*
+ * Reads a `.tsfile` written by `TsFileWriter`: the trailing index is
+ * loaded up front (sorted by device then sensor) so `search_meta` can
+ * binary search it instead of scanning the file.
+ */
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::tsfile::format::{ChunkMeta, MAGIC};
+use crate::{Error, Result};
+
+/// One `(device, sensor, meta)` entry, kept sorted for binary search.
+struct Entry {
+    device: String,
+    sensor: String,
+    meta: ChunkMeta,
+}
+
+pub struct TsFileReader {
+    file: File,
+    // sorted by (device, sensor)
+    entries: Vec<Entry>,
+}
+
+impl TsFileReader {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let mut file = File::open(path)?;
+
+        let mut header = [0u8; 4];
+        file.read_exact(&mut header)?;
+        if &header != MAGIC {
+            return Err(Error::Other("not a tsfile (bad magic)".to_string()));
+        }
+
+        file.seek(SeekFrom::End(-12))?;
+        let mut footer = [0u8; 12];
+        file.read_exact(&mut footer)?;
+        if &footer[8..12] != MAGIC {
+            return Err(Error::Other("not a tsfile (bad footer)".to_string()));
+        }
+        let index_offset = u64::from_le_bytes(footer[0..8].try_into().unwrap());
+
+        file.seek(SeekFrom::Start(index_offset))?;
+        let mut entries = Vec::new();
+        let num_devices = read_u64(&mut file)?;
+        for _ in 0..num_devices {
+            let device = read_string(&mut file)?;
+            let num_sensors = read_u64(&mut file)?;
+            for _ in 0..num_sensors {
+                let sensor = read_string(&mut file)?;
+                let offset = read_u64(&mut file)?;
+                let num_points = read_u64(&mut file)?;
+                let min_timestamp = read_i64(&mut file)?;
+                let max_timestamp = read_i64(&mut file)?;
+                entries.push(Entry {
+                    device: device.clone(),
+                    sensor,
+                    meta: ChunkMeta {
+                        offset,
+                        num_points,
+                        min_timestamp,
+                        max_timestamp,
+                    },
+                });
+            }
+        }
+        entries.sort_by(|a, b| (&a.device, &a.sensor).cmp(&(&b.device, &b.sensor)));
+
+        Ok(Self { file, entries })
+    }
+
+    /// Every distinct device path present in the file, in sorted order.
+    pub fn device_iter(&self) -> impl Iterator<Item = &str> {
+        let mut seen: Vec<&str> = Vec::new();
+        for entry in &self.entries {
+            if seen.last() != Some(&entry.device.as_str()) {
+                seen.push(&entry.device);
+            }
+        }
+        seen.into_iter()
+    }
+
+    /// Every sensor name recorded for `device`, in sorted order.
+    pub fn sensor_iter<'a>(&'a self, device: &'a str) -> impl Iterator<Item = &'a str> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.device == device)
+            .map(|entry| entry.sensor.as_str())
+    }
+
+    /// Binary search the index for `device` + `sensor`.
+    pub fn search_meta(&self, device: &str, sensor: &str) -> Option<&ChunkMeta> {
+        let key = (device, sensor);
+        self.entries
+            .binary_search_by(|entry| (entry.device.as_str(), entry.sensor.as_str()).cmp(&key))
+            .ok()
+            .map(|idx| &self.entries[idx].meta)
+    }
+
+    /// Read every `(timestamp, value)` point for `device` + `sensor`.
+    pub fn read_points(&mut self, device: &str, sensor: &str) -> Result<Vec<(i64, f32)>> {
+        let Some(meta) = self.search_meta(device, sensor) else {
+            return Ok(Vec::new());
+        };
+        let meta = meta.clone();
+
+        self.file.seek(SeekFrom::Start(meta.offset))?;
+        let mut points = Vec::with_capacity(meta.num_points as usize);
+        for _ in 0..meta.num_points {
+            let ts = read_i64(&mut self.file)?;
+            let mut value_bytes = [0u8; 4];
+            self.file.read_exact(&mut value_bytes)?;
+            points.push((ts, f32::from_le_bytes(value_bytes)));
+        }
+        Ok(points)
+    }
+}
+
+fn read_u64<R: Read>(r: &mut R) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_i64<R: Read>(r: &mut R) -> Result<i64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(i64::from_le_bytes(buf))
+}
+
+fn read_string<R: Read>(r: &mut R) -> Result<String> {
+    let len = read_u64(r)? as usize;
+    let mut buf = vec![0u8; len];
+    r.read_exact(&mut buf)?;
+    String::from_utf8(buf).map_err(|e| Error::Other(e.to_string()))
+}
@@ -0,0 +1,124 @@
+// src/tsfile/writer.rs
+/*
+ * Writes a `.tsfile`: readings are buffered by device path and sensor
+ * name, then serialized as one chunk per device+sensor on `finish`.
+ */
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::sensor::Reading;
+use crate::tsfile::format::MAGIC;
+use crate::Result;
+
+#[derive(Default)]
+struct Series {
+    points: Vec<(i64, f32)>,
+}
+
+/// Accumulates readings in memory, grouped by device path and sensor
+/// name, and serializes them to a `.tsfile` on [`TsFileWriter::finish`].
+pub struct TsFileWriter {
+    // device path -> sensor name -> points
+    series: BTreeMap<String, BTreeMap<String, Series>>,
+}
+
+impl TsFileWriter {
+    pub fn new() -> Self {
+        Self {
+            series: BTreeMap::new(),
+        }
+    }
+
+    /// Buffer a reading under `device` (e.g. `root.group.device`).
+    pub fn write_reading(&mut self, device: &str, reading: &Reading) {
+        let Some(value) = reading.value else {
+            return;
+        };
+        let timestamp = reading
+            .timestamp
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64;
+
+        self.series
+            .entry(device.to_string())
+            .or_default()
+            .entry(reading.name.clone())
+            .or_default()
+            .points
+            .push((timestamp, value));
+    }
+
+    /// Serialize all buffered readings to `path`.
+    pub fn finish(self, path: impl AsRef<Path>) -> Result<()> {
+        let file = File::create(path)?;
+        let mut out = BufWriter::new(file);
+        out.write_all(MAGIC)?;
+
+        // device -> sensor -> (offset, num_points, min_ts, max_ts)
+        let mut index: BTreeMap<String, BTreeMap<String, (u64, u64, i64, i64)>> = BTreeMap::new();
+        let mut offset = MAGIC.len() as u64;
+
+        for (device, sensors) in &self.series {
+            let mut sensor_index = BTreeMap::new();
+            for (sensor, series) in sensors {
+                let chunk_offset = offset;
+                let min_ts = series.points.iter().map(|(ts, _)| *ts).min().unwrap_or(0);
+                let max_ts = series.points.iter().map(|(ts, _)| *ts).max().unwrap_or(0);
+
+                for (ts, value) in &series.points {
+                    out.write_all(&ts.to_le_bytes())?;
+                    out.write_all(&value.to_le_bytes())?;
+                    offset += 12;
+                }
+
+                sensor_index.insert(
+                    sensor.clone(),
+                    (chunk_offset, series.points.len() as u64, min_ts, max_ts),
+                );
+            }
+            index.insert(device.clone(), sensor_index);
+        }
+
+        let index_offset = offset;
+        write_index(&mut out, &index)?;
+        out.write_all(&index_offset.to_le_bytes())?;
+        out.write_all(MAGIC)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+impl Default for TsFileWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_index<W: Write>(
+    out: &mut W,
+    index: &BTreeMap<String, BTreeMap<String, (u64, u64, i64, i64)>>,
+) -> Result<()> {
+    out.write_all(&(index.len() as u64).to_le_bytes())?;
+    for (device, sensors) in index {
+        write_string(out, device)?;
+        out.write_all(&(sensors.len() as u64).to_le_bytes())?;
+        for (sensor, (chunk_offset, num_points, min_ts, max_ts)) in sensors {
+            write_string(out, sensor)?;
+            out.write_all(&chunk_offset.to_le_bytes())?;
+            out.write_all(&num_points.to_le_bytes())?;
+            out.write_all(&min_ts.to_le_bytes())?;
+            out.write_all(&max_ts.to_le_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+fn write_string<W: Write>(out: &mut W, s: &str) -> Result<()> {
+    out.write_all(&(s.len() as u64).to_le_bytes())?;
+    out.write_all(s.as_bytes())?;
+    Ok(())
+}